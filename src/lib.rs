@@ -2,12 +2,188 @@ use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
-/// Template files embedded in the binary
+/// Template files embedded in the binary, rendered through [`render::environment`]
 pub mod templates {
-    pub const PROJECT_GITIGNORE: &str = include_str!("../templates/Project.gitignore");
-    pub const GODOT_GITIGNORE: &str = include_str!("../templates/Godot.gitignore");
-    pub const RUST_GITIGNORE: &str = include_str!("../templates/Rust.gitignore");
-    pub const GODOT_GDEXTENSION: &str = include_str!("../templates/Godot.gdextension");
+    pub const PROJECT_GITIGNORE: &str = include_str!("../templates/Project.gitignore.j2");
+    pub const GODOT_GITIGNORE: &str = include_str!("../templates/Godot.gitignore.j2");
+    pub const RUST_GITIGNORE: &str = include_str!("../templates/Rust.gitignore.j2");
+    pub const GODOT_GDEXTENSION: &str = include_str!("../templates/Godot.gdextension.j2");
+    pub const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.j2");
+    pub const LIB_RS: &str = include_str!("../templates/lib.rs.j2");
+    pub const PROJECT_GODOT: &str = include_str!("../templates/project.godot.j2");
+    pub const MAKEFILE_TOML: &str = include_str!("../templates/Makefile.toml.j2");
+}
+
+/// Renders the embedded [`templates`] against a [`RenderContext`].
+pub mod render {
+    use super::{templates, CreateError};
+    use minijinja::Environment;
+
+    /// Values every embedded template is rendered with.
+    #[derive(Debug, Clone, serde::Serialize)]
+    pub struct RenderContext {
+        /// Name of the project as given on the command line.
+        pub project_name: String,
+        /// Sanitized Rust crate/package name (dashes become underscores).
+        pub crate_name: String,
+        /// PascalCase `ExtensionLibrary` struct name, e.g. `MyProjectExtension`.
+        pub struct_name: String,
+        /// Godot compatibility version written into `.gdextension` (`compatibility_minimum`).
+        pub godot_version: String,
+        /// Rust edition written into the generated `Cargo.toml`.
+        pub rust_edition: String,
+        /// Resolved gdext template tag (see [`super::resolve_template_tag`]).
+        pub gdext_tag: String,
+        /// `.gdextension` `[libraries]` entries, one per supported platform/profile.
+        pub gdextension_libraries: Vec<super::GdextensionLibrary>,
+    }
+
+    impl RenderContext {
+        pub fn new(project_name: &str, rust_edition: &str, gdext_tag: &str) -> Self {
+            let crate_name = sanitize_crate_name(project_name);
+            let struct_name = ensure_identifier(format!("{}Extension", pascal_case(project_name)));
+            let gdextension_libraries = super::gdextension_libraries(&crate_name);
+            Self {
+                project_name: project_name.to_string(),
+                crate_name,
+                struct_name,
+                godot_version: super::DEFAULT_GODOT_VERSION.to_string(),
+                rust_edition: rust_edition.to_string(),
+                gdext_tag: gdext_tag.to_string(),
+                gdextension_libraries,
+            }
+        }
+    }
+
+    /// Sanitize a project name into a valid Rust crate name.
+    fn sanitize_crate_name(name: &str) -> String {
+        ensure_identifier(name.replace('-', "_"))
+    }
+
+    /// Prefix an identifier with `_` if it would otherwise start with a digit,
+    /// which is illegal for both Rust identifiers and Cargo package names.
+    fn ensure_identifier(name: String) -> String {
+        match name.chars().next() {
+            Some(first) if first.is_ascii_digit() => format!("_{}", name),
+            _ => name,
+        }
+    }
+
+    /// Convert a project name into `PascalCase` for use in generated identifiers.
+    fn pascal_case(name: &str) -> String {
+        name.split(|c: char| c == '-' || c == '_' || c == ' ')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// Build the minijinja [`Environment`] with every embedded template registered.
+    pub fn environment() -> Environment<'static> {
+        let mut env = Environment::new();
+        env.add_template("Project.gitignore.j2", templates::PROJECT_GITIGNORE)
+            .expect("embedded template is valid");
+        env.add_template("Godot.gitignore.j2", templates::GODOT_GITIGNORE)
+            .expect("embedded template is valid");
+        env.add_template("Rust.gitignore.j2", templates::RUST_GITIGNORE)
+            .expect("embedded template is valid");
+        env.add_template("Godot.gdextension.j2", templates::GODOT_GDEXTENSION)
+            .expect("embedded template is valid");
+        env.add_template("Cargo.toml.j2", templates::CARGO_TOML)
+            .expect("embedded template is valid");
+        env.add_template("lib.rs.j2", templates::LIB_RS)
+            .expect("embedded template is valid");
+        env.add_template("project.godot.j2", templates::PROJECT_GODOT)
+            .expect("embedded template is valid");
+        env.add_template("Makefile.toml.j2", templates::MAKEFILE_TOML)
+            .expect("embedded template is valid");
+        env
+    }
+
+    /// Render a registered template by name against `ctx`.
+    pub fn render(
+        env: &Environment<'_>,
+        name: &str,
+        ctx: &RenderContext,
+    ) -> Result<String, CreateError> {
+        env.get_template(name)
+            .and_then(|tpl| tpl.render(ctx))
+            .map_err(CreateError::Template)
+    }
+}
+
+/// The gdext template tag used when the user doesn't pin one explicitly.
+///
+/// This tracks the last `godot-rust/gdext` tag this crate was verified against;
+/// bump it when a newer tag is known to work.
+pub const DEFAULT_TEMPLATE_TAG: &str = "v0.2.4";
+
+/// Godot compatibility version written into generated projects.
+pub const DEFAULT_GODOT_VERSION: &str = "4.1";
+
+/// Rust edition written into generated projects when none is requested.
+pub const DEFAULT_RUST_EDITION: &str = "2024";
+
+/// Resolve a requested template tag, falling back to [`DEFAULT_TEMPLATE_TAG`]
+/// when the user didn't pin one or asked for `"latest"`.
+fn resolve_template_tag(requested: Option<&str>) -> String {
+    match requested {
+        None => DEFAULT_TEMPLATE_TAG.to_string(),
+        Some(tag) if tag.eq_ignore_ascii_case("latest") => DEFAULT_TEMPLATE_TAG.to_string(),
+        Some(tag) => tag.to_string(),
+    }
+}
+
+/// Resolve a requested Rust edition, falling back to [`DEFAULT_RUST_EDITION`].
+fn resolve_rust_edition(requested: Option<&str>) -> String {
+    requested
+        .map(str::to_string)
+        .unwrap_or_else(|| DEFAULT_RUST_EDITION.to_string())
+}
+
+/// A single `.gdextension` `[libraries]` entry: a platform/profile key mapped to the
+/// path of the `cdylib` Cargo produces for it.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GdextensionLibrary {
+    pub platform: String,
+    pub path: String,
+}
+
+/// Target platform/profile combinations `.gdextension` `[libraries]` sections cover,
+/// along with the OS each one builds for.
+const GDEXTENSION_TARGETS: &[(&str, &str, &str)] = &[
+    ("linux.debug.x86_64", "debug", "linux"),
+    ("linux.release.x86_64", "release", "linux"),
+    ("windows.debug.x86_64", "debug", "windows"),
+    ("windows.release.x86_64", "release", "windows"),
+    ("macos.debug", "debug", "macos"),
+    ("macos.release", "release", "macos"),
+];
+
+/// Build the `[libraries]` entries for every supported platform/profile, pointing at
+/// the `cdylib` the Rust crate produces under `rust/target/<profile>/`, named per
+/// that platform's `cdylib` naming convention (`lib*.so`, `*.dll`, `lib*.dylib`).
+fn gdextension_libraries(crate_name: &str) -> Vec<GdextensionLibrary> {
+    GDEXTENSION_TARGETS
+        .iter()
+        .map(|(platform, profile, os)| {
+            let filename = match *os {
+                "linux" => format!("lib{}.so", crate_name),
+                "windows" => format!("{}.dll", crate_name),
+                "macos" => format!("lib{}.dylib", crate_name),
+                _ => unreachable!("unhandled gdextension target OS: {os}"),
+            };
+            GdextensionLibrary {
+                platform: platform.to_string(),
+                path: format!("res://../rust/target/{}/{}", profile, filename),
+            }
+        })
+        .collect()
 }
 
 /// Errors that can occur during project creation
@@ -15,6 +191,8 @@ pub mod templates {
 pub enum CreateError {
     Io(io::Error),
     ProjectAlreadyExists(PathBuf),
+    Template(minijinja::Error),
+    GitInit(io::Error),
 }
 
 impl From<io::Error> for CreateError {
@@ -30,17 +208,44 @@ impl std::fmt::Display for CreateError {
             CreateError::ProjectAlreadyExists(path) => {
                 write!(f, "Project directory already exists: {}", path.display())
             }
+            CreateError::Template(err) => write!(f, "Template error: {}", err),
+            CreateError::GitInit(err) => write!(f, "Failed to run `git init`: {}", err),
         }
     }
 }
 
 impl std::error::Error for CreateError {}
 
+/// Version control behavior to apply to a newly scaffolded project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Vcs {
+    /// Run `git init` and write the `.gitignore` files (the default).
+    #[default]
+    Git,
+    /// Skip version control entirely.
+    None,
+}
+
+/// Build tool glue to generate alongside a scaffolded project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BuildTool {
+    /// Emit a `Makefile.toml` with `build`/`run`/`export` tasks (the default).
+    #[default]
+    CargoMake,
+    /// Don't generate any build glue.
+    None,
+}
+
 /// Configuration for creating a new project
 #[derive(Debug, Clone)]
 pub struct ProjectConfig {
     pub name: String,
     pub base_path: PathBuf,
+    pub template_tag: Option<String>,
+    pub rust_edition: Option<String>,
+    pub vcs: Vcs,
+    pub force: bool,
+    pub build_tool: BuildTool,
 }
 
 impl ProjectConfig {
@@ -48,6 +253,11 @@ impl ProjectConfig {
         Self {
             name,
             base_path: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+            template_tag: None,
+            rust_edition: None,
+            vcs: Vcs::default(),
+            force: false,
+            build_tool: BuildTool::default(),
         }
     }
 
@@ -56,6 +266,37 @@ impl ProjectConfig {
         self
     }
 
+    /// Pin the gdext template to a specific tag (e.g. `"v0.2.4"`), or `"latest"`
+    /// to resolve the crate's known-good default.
+    pub fn with_template_tag(mut self, tag: String) -> Self {
+        self.template_tag = Some(tag);
+        self
+    }
+
+    /// Set the Rust edition written into the generated `Cargo.toml`.
+    pub fn with_rust_edition(mut self, edition: String) -> Self {
+        self.rust_edition = Some(edition);
+        self
+    }
+
+    /// Set the version control behavior for the generated project.
+    pub fn with_vcs(mut self, vcs: Vcs) -> Self {
+        self.vcs = vcs;
+        self
+    }
+
+    /// Regenerate into an existing directory instead of failing.
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Set the build tool glue to generate alongside the project.
+    pub fn with_build_tool(mut self, build_tool: BuildTool) -> Self {
+        self.build_tool = build_tool;
+        self
+    }
+
     pub fn project_path(&self) -> PathBuf {
         self.base_path.join(&self.name)
     }
@@ -66,21 +307,40 @@ pub fn create_project(config: &ProjectConfig) -> Result<(), CreateError> {
     let project_path = config.project_path();
 
     // Check if project already exists
-    if project_path.exists() {
+    if project_path.exists() && !config.force {
         return Err(CreateError::ProjectAlreadyExists(project_path));
     }
 
     // Create project directory structure
     create_directory_structure(&project_path)?;
 
+    // Resolve the gdext template tag and Rust edition, and build the render context
+    let template_tag = resolve_template_tag(config.template_tag.as_deref());
+    let rust_edition = resolve_rust_edition(config.rust_edition.as_deref());
+    let ctx = render::RenderContext::new(&config.name, &rust_edition, &template_tag);
+    let env = render::environment();
+
     // Generate template files
-    generate_template_files(&project_path, &config.name)?;
+    generate_template_files(&project_path, &env, &ctx, config.vcs)?;
 
     // Initialize Godot project
-    initialize_godot_project(&project_path, &config.name)?;
+    initialize_godot_project(&project_path, &env, &ctx)?;
+
+    // Record the resolved gdext tag so the project is reproducible
+    fs::write(project_path.join(".tag"), &template_tag)?;
 
     // Initialize Rust project
-    initialize_rust_project(&project_path)?;
+    initialize_rust_project(&project_path, &env, &ctx)?;
+
+    // Generate build tool glue
+    if config.build_tool == BuildTool::CargoMake {
+        generate_makefile(&project_path, &env, &ctx)?;
+    }
+
+    // Initialize version control
+    if config.vcs == Vcs::Git {
+        init_git_repo(&project_path)?;
+    }
 
     Ok(())
 }
@@ -91,88 +351,109 @@ fn create_directory_structure(project_path: &Path) -> Result<(), CreateError> {
     fs::create_dir_all(project_path)?;
 
     // Create godot subdirectory
-    fs::create_dir(project_path.join("godot"))?;
+    fs::create_dir_all(project_path.join("godot"))?;
 
     // Create rust subdirectory and src
-    fs::create_dir(project_path.join("rust"))?;
-    fs::create_dir(project_path.join("rust/src"))?;
+    fs::create_dir_all(project_path.join("rust/src"))?;
 
     Ok(())
 }
 
 /// Generate template files from embedded templates
-fn generate_template_files(project_path: &Path, _project_name: &str) -> Result<(), CreateError> {
-    // Write root .gitignore
-    fs::write(
-        project_path.join(".gitignore"),
-        templates::PROJECT_GITIGNORE,
-    )?;
-
-    // Write godot .gitignore
-    fs::write(
-        project_path.join("godot/.gitignore"),
-        templates::GODOT_GITIGNORE,
-    )?;
-
+fn generate_template_files(
+    project_path: &Path,
+    env: &minijinja::Environment,
+    ctx: &render::RenderContext,
+    vcs: Vcs,
+) -> Result<(), CreateError> {
     // Write godot .gdextension
     fs::write(
         project_path.join("godot/.gdextension"),
-        templates::GODOT_GDEXTENSION,
+        render::render(env, "Godot.gdextension.j2", ctx)?,
     )?;
 
-    // Write rust .gitignore
-    fs::write(
-        project_path.join("rust/.gitignore"),
-        templates::RUST_GITIGNORE,
-    )?;
+    // Seed .gitignore files only when version control is enabled
+    if vcs == Vcs::Git {
+        fs::write(
+            project_path.join(".gitignore"),
+            render::render(env, "Project.gitignore.j2", ctx)?,
+        )?;
+
+        fs::write(
+            project_path.join("godot/.gitignore"),
+            render::render(env, "Godot.gitignore.j2", ctx)?,
+        )?;
+
+        fs::write(
+            project_path.join("rust/.gitignore"),
+            render::render(env, "Rust.gitignore.j2", ctx)?,
+        )?;
+    }
 
     Ok(())
 }
 
-/// Initialize the Godot project
-fn initialize_godot_project(project_path: &Path, project_name: &str) -> Result<(), CreateError> {
-    let godot_project_content = format!("[application]\nconfig/name=\"{}-godot\"\n", project_name);
+/// Run `git init` in the newly scaffolded project directory.
+fn init_git_repo(project_path: &Path) -> Result<(), CreateError> {
+    let status = std::process::Command::new("git")
+        .arg("init")
+        .arg("--quiet")
+        .current_dir(project_path)
+        .status()
+        .map_err(CreateError::GitInit)?;
+
+    if !status.success() {
+        return Err(CreateError::GitInit(io::Error::other(
+            "`git init` exited with a non-zero status",
+        )));
+    }
 
+    Ok(())
+}
+
+/// Initialize the Godot project
+fn initialize_godot_project(
+    project_path: &Path,
+    env: &minijinja::Environment,
+    ctx: &render::RenderContext,
+) -> Result<(), CreateError> {
     fs::write(
         project_path.join("godot/project.godot"),
-        godot_project_content,
+        render::render(env, "project.godot.j2", ctx)?,
     )?;
 
     Ok(())
 }
 
 /// Initialize the Rust project
-fn initialize_rust_project(project_path: &Path) -> Result<(), CreateError> {
-    // Write lib.rs
-    let lib_rs_content = r#"use godot::prelude::*;
-
-struct MyExtension;
-
-#[gdextension]
-unsafe impl ExtensionLibrary for MyExtension {}
-"#;
-
-    fs::write(project_path.join("rust/src/lib.rs"), lib_rs_content)?;
-
-    // Write Cargo.toml
-    let cargo_toml_content = r#"[package]
-name = "rust"
-version = "0.1.0"
-edition = "2024"
-
-[lib]
-crate-type = ["cdylib"]
+fn initialize_rust_project(
+    project_path: &Path,
+    env: &minijinja::Environment,
+    ctx: &render::RenderContext,
+) -> Result<(), CreateError> {
+    fs::write(
+        project_path.join("rust/src/lib.rs"),
+        render::render(env, "lib.rs.j2", ctx)?,
+    )?;
 
-[dependencies]
-godot = { git = "https://github.com/godot-rust/gdext" }
+    fs::write(
+        project_path.join("rust/Cargo.toml"),
+        render::render(env, "Cargo.toml.j2", ctx)?,
+    )?;
 
-[profile.dev]
-opt-level = 1
-[profile.dev.package."*"]
-opt-level = 1
-"#;
+    Ok(())
+}
 
-    fs::write(project_path.join("rust/Cargo.toml"), cargo_toml_content)?;
+/// Generate the cargo-make `Makefile.toml` with build/run/export tasks
+fn generate_makefile(
+    project_path: &Path,
+    env: &minijinja::Environment,
+    ctx: &render::RenderContext,
+) -> Result<(), CreateError> {
+    fs::write(
+        project_path.join("Makefile.toml"),
+        render::render(env, "Makefile.toml.j2", ctx)?,
+    )?;
 
     Ok(())
 }
@@ -206,6 +487,8 @@ mod tests {
         assert!(project_path.join("rust/.gitignore").exists());
         assert!(project_path.join("rust/Cargo.toml").exists());
         assert!(project_path.join("rust/src/lib.rs").exists());
+        assert!(project_path.join(".tag").exists());
+        assert!(project_path.join("Makefile.toml").exists());
 
         // Verify content of project.godot
         let godot_content = fs::read_to_string(project_path.join("godot/project.godot")).unwrap();
@@ -214,15 +497,49 @@ mod tests {
         // Verify content of lib.rs
         let lib_rs_content = fs::read_to_string(project_path.join("rust/src/lib.rs")).unwrap();
         assert!(lib_rs_content.contains("use godot::prelude::*"));
-        assert!(lib_rs_content.contains("struct MyExtension"));
-        assert!(lib_rs_content.contains("#[gdextension]"));
+        assert!(lib_rs_content.contains("struct TestprojectExtension"));
+        assert!(lib_rs_content.contains("#[gdextension(entry_symbol = gdext_testproject_init)]"));
 
-        // Verify content of Cargo.toml
+        // Verify content of Cargo.toml: defaults to the crate's known-good tag
         let cargo_content = fs::read_to_string(project_path.join("rust/Cargo.toml")).unwrap();
+        assert!(cargo_content.contains("name = \"testproject\""));
         assert!(cargo_content.contains("crate-type = [\"cdylib\"]"));
-        assert!(
-            cargo_content.contains("godot = { git = \"https://github.com/godot-rust/gdext\" }")
-        );
+        assert!(cargo_content.contains(&format!(
+            "godot = {{ git = \"https://github.com/godot-rust/gdext\", tag = \"{}\" }}",
+            DEFAULT_TEMPLATE_TAG
+        )));
+
+        // Verify the .tag file records the resolved tag
+        let tag_content = fs::read_to_string(project_path.join(".tag")).unwrap();
+        assert_eq!(tag_content, DEFAULT_TEMPLATE_TAG);
+    }
+
+    #[test]
+    fn test_create_project_with_pinned_template_tag() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig::new("testproject".to_string())
+            .with_base_path(temp_dir.path().to_path_buf())
+            .with_template_tag("v1.5.0".to_string());
+
+        let result = create_project(&config);
+        assert!(result.is_ok());
+
+        let project_path = config.project_path();
+        let tag_content = fs::read_to_string(project_path.join(".tag")).unwrap();
+        assert_eq!(tag_content, "v1.5.0");
+
+        let cargo_content = fs::read_to_string(project_path.join("rust/Cargo.toml")).unwrap();
+        assert!(cargo_content.contains(
+            "godot = { git = \"https://github.com/godot-rust/gdext\", tag = \"v1.5.0\" }"
+        ));
+    }
+
+    #[test]
+    fn test_resolve_template_tag() {
+        assert_eq!(resolve_template_tag(None), DEFAULT_TEMPLATE_TAG);
+        assert_eq!(resolve_template_tag(Some("latest")), DEFAULT_TEMPLATE_TAG);
+        assert_eq!(resolve_template_tag(Some("LATEST")), DEFAULT_TEMPLATE_TAG);
+        assert_eq!(resolve_template_tag(Some("v1.5.0")), "v1.5.0");
     }
 
     #[test]
@@ -243,6 +560,99 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_project_with_force_overwrites_existing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig::new("testproject".to_string())
+            .with_base_path(temp_dir.path().to_path_buf())
+            .with_force(true);
+
+        // Create project first time
+        create_project(&config).unwrap();
+
+        // Re-running with force set should regenerate instead of erroring
+        let result = create_project(&config);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_create_project_with_rust_edition() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig::new("testproject".to_string())
+            .with_base_path(temp_dir.path().to_path_buf())
+            .with_rust_edition("2021".to_string());
+
+        create_project(&config).unwrap();
+
+        let cargo_content =
+            fs::read_to_string(config.project_path().join("rust/Cargo.toml")).unwrap();
+        assert!(cargo_content.contains("edition = \"2021\""));
+    }
+
+    #[test]
+    fn test_create_project_with_vcs_none_skips_gitignores_and_git() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig::new("testproject".to_string())
+            .with_base_path(temp_dir.path().to_path_buf())
+            .with_vcs(Vcs::None);
+
+        create_project(&config).unwrap();
+
+        let project_path = config.project_path();
+        assert!(!project_path.join(".gitignore").exists());
+        assert!(!project_path.join("godot/.gitignore").exists());
+        assert!(!project_path.join("rust/.gitignore").exists());
+        assert!(!project_path.join(".git").exists());
+    }
+
+    #[test]
+    fn test_create_project_makefile_has_build_run_export_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig::new("testproject".to_string())
+            .with_base_path(temp_dir.path().to_path_buf());
+
+        create_project(&config).unwrap();
+
+        let makefile_content =
+            fs::read_to_string(config.project_path().join("Makefile.toml")).unwrap();
+        assert!(makefile_content.contains("[tasks.build]"));
+        assert!(makefile_content.contains("[tasks.run]"));
+        assert!(makefile_content.contains("[tasks.export]"));
+    }
+
+    #[test]
+    fn test_makefile_does_not_copy_library_the_gdextension_does_not_reference() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig::new("testproject".to_string())
+            .with_base_path(temp_dir.path().to_path_buf());
+
+        create_project(&config).unwrap();
+
+        let project_path = config.project_path();
+        let makefile_content = fs::read_to_string(project_path.join("Makefile.toml")).unwrap();
+        let gdextension_content =
+            fs::read_to_string(project_path.join("godot/.gdextension")).unwrap();
+
+        // The Makefile must not copy the cdylib into godot/: the .gdextension
+        // references rust/target directly, so a godot/ copy would be a stray,
+        // unreferenced binary that git would happily pick up.
+        assert!(!makefile_content.contains("godot/lib"));
+        assert!(!makefile_content.contains(r"godot\"));
+        assert!(gdextension_content.contains("res://../rust/target/debug/libtestproject.so"));
+    }
+
+    #[test]
+    fn test_create_project_with_build_tool_none_skips_makefile() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = ProjectConfig::new("testproject".to_string())
+            .with_base_path(temp_dir.path().to_path_buf())
+            .with_build_tool(BuildTool::None);
+
+        create_project(&config).unwrap();
+
+        assert!(!config.project_path().join("Makefile.toml").exists());
+    }
+
     #[test]
     fn test_project_config_project_path() {
         let config =
@@ -250,4 +660,89 @@ mod tests {
 
         assert_eq!(config.project_path(), PathBuf::from("/test/path/myproject"));
     }
+
+    #[test]
+    fn test_render_context_derives_names_from_project_name() {
+        let ctx = render::RenderContext::new("my-cool-game", "2021", "v1.5.0");
+        assert_eq!(ctx.crate_name, "my_cool_game");
+        assert_eq!(ctx.struct_name, "MyCoolGameExtension");
+        assert_eq!(ctx.rust_edition, "2021");
+        assert_eq!(ctx.gdext_tag, "v1.5.0");
+    }
+
+    #[test]
+    fn test_render_context_escapes_leading_digit_in_derived_names() {
+        let ctx = render::RenderContext::new("3d-platformer", "2024", "v1.5.0");
+        assert_eq!(ctx.crate_name, "_3d_platformer");
+        assert_eq!(ctx.struct_name, "_3dPlatformerExtension");
+    }
+
+    #[test]
+    fn test_create_project_renders_entry_symbol_from_crate_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let config =
+            ProjectConfig::new("my-game".to_string()).with_base_path(temp_dir.path().to_path_buf());
+
+        create_project(&config).unwrap();
+
+        let gdextension_content =
+            fs::read_to_string(config.project_path().join("godot/.gdextension")).unwrap();
+        assert!(gdextension_content.contains("entry_symbol = \"gdext_my_game_init\""));
+    }
+
+    #[test]
+    fn test_gdextension_libraries_maps_each_platform_to_prefixed_filename() {
+        let libraries = gdextension_libraries("my_game");
+        let path_for = |platform: &str| {
+            libraries
+                .iter()
+                .find(|lib| lib.platform == platform)
+                .unwrap_or_else(|| panic!("missing platform key: {platform}"))
+                .path
+                .clone()
+        };
+
+        assert_eq!(
+            path_for("linux.debug.x86_64"),
+            "res://../rust/target/debug/libmy_game.so"
+        );
+        assert_eq!(
+            path_for("linux.release.x86_64"),
+            "res://../rust/target/release/libmy_game.so"
+        );
+        assert_eq!(
+            path_for("windows.debug.x86_64"),
+            "res://../rust/target/debug/my_game.dll"
+        );
+        assert_eq!(
+            path_for("windows.release.x86_64"),
+            "res://../rust/target/release/my_game.dll"
+        );
+        assert_eq!(
+            path_for("macos.debug"),
+            "res://../rust/target/debug/libmy_game.dylib"
+        );
+        assert_eq!(
+            path_for("macos.release"),
+            "res://../rust/target/release/libmy_game.dylib"
+        );
+    }
+
+    #[test]
+    fn test_create_project_gdextension_libraries_use_sanitized_crate_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let config =
+            ProjectConfig::new("my-game".to_string()).with_base_path(temp_dir.path().to_path_buf());
+
+        create_project(&config).unwrap();
+
+        let gdextension_content =
+            fs::read_to_string(config.project_path().join("godot/.gdextension")).unwrap();
+        assert!(gdextension_content
+            .contains("linux.debug.x86_64 = \"res://../rust/target/debug/libmy_game.so\""));
+        assert!(gdextension_content
+            .contains("windows.debug.x86_64 = \"res://../rust/target/debug/my_game.dll\""));
+        assert!(gdextension_content
+            .contains("macos.debug = \"res://../rust/target/debug/libmy_game.dylib\""));
+    }
 }
@@ -1,5 +1,5 @@
 use clap::Parser;
-use create_godotrs::{ProjectConfig, create_project};
+use create_godotrs::{create_project, BuildTool, ProjectConfig, Vcs};
 use std::process;
 
 /// Create a new Godot project with Rust
@@ -9,12 +9,54 @@ use std::process;
 struct Args {
     /// Name of the project to create
     name: String,
+
+    /// gdext template tag to pin the project to (e.g. "v1.5.0"), or "latest"
+    /// to resolve the crate's known-good default
+    template_tag: Option<String>,
+
+    /// Directory to create the project in (defaults to the current directory)
+    #[arg(long)]
+    path: Option<std::path::PathBuf>,
+
+    /// Rust edition for the generated crate
+    #[arg(long, value_parser = ["2021", "2024"], default_value = "2024")]
+    edition: String,
+
+    /// Initialize (or skip) version control for the generated project
+    #[arg(long, value_parser = ["git", "none"], default_value = "git")]
+    vcs: String,
+
+    /// Overwrite an existing directory instead of failing
+    #[arg(long, alias = "overwrite")]
+    force: bool,
+
+    /// Build tool glue to generate alongside the project
+    #[arg(long = "build-tool", value_parser = ["cargo-make", "none"], default_value = "cargo-make")]
+    build_tool: String,
 }
 
 fn main() {
     let args = Args::parse();
 
-    let config = ProjectConfig::new(args.name);
+    let mut config = ProjectConfig::new(args.name)
+        .with_rust_edition(args.edition)
+        .with_vcs(if args.vcs == "none" {
+            Vcs::None
+        } else {
+            Vcs::Git
+        })
+        .with_force(args.force)
+        .with_build_tool(if args.build_tool == "none" {
+            BuildTool::None
+        } else {
+            BuildTool::CargoMake
+        });
+    if let Some(path) = args.path {
+        config = config.with_base_path(path);
+    }
+    if let Some(tag) = args.template_tag {
+        config = config.with_template_tag(tag);
+    }
 
     match create_project(&config) {
         Ok(()) => {